@@ -0,0 +1,32 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+#[path = "src/header.rs"]
+mod header;
+#[path = "src/registry.rs"]
+mod registry;
+
+fn main() {
+    let out_dir = PathBuf::from(env::var("OUT_DIR").unwrap());
+
+    // This build script itself always compiles for the host, so the
+    // `#[cfg(target_arch)]` gates on the ABI-variant modules in `lib.rs`
+    // can't be reused here. Cargo instead tells a build script which arch
+    // it's building the *library* for via `CARGO_CFG_TARGET_ARCH`; filter
+    // the ABI-tagged entries against that so the generated header never
+    // declares a prototype for a symbol the target doesn't actually define.
+    let target_arch = env::var("CARGO_CFG_TARGET_ARCH").unwrap();
+    let functions: Vec<_> = registry::FUNCTIONS
+        .iter()
+        .copied()
+        .filter(|f| f.arch.is_none_or(|arch| arch == target_arch))
+        .collect();
+
+    let header = header::render(registry::STRUCTS, &functions);
+    fs::write(out_dir.join("testlib.h"), header).expect("failed to write testlib.h");
+
+    println!("cargo:rerun-if-changed=src/header.rs");
+    println!("cargo:rerun-if-changed=src/registry.rs");
+    println!("cargo:rerun-if-changed=build.rs");
+}
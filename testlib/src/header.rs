@@ -0,0 +1,77 @@
+//! Descriptor types consumed by `build.rs` to generate a C header for every
+//! symbol exported by this crate.
+//!
+//! [`crate::registry`] holds one [`StructDescriptor`]/[`FunctionDescriptor`]
+//! per symbol the `impl_tuple!`/`impl_sub!` macros emit. The list is kept by
+//! hand in step with the macro invocations in `lib.rs`, so a new
+//! `impl_tuple!`/`impl_sub!` line needs a matching registry entry to show up
+//! in the generated header.
+
+pub struct FieldDescriptor {
+    pub name: &'static str,
+    pub c_type: &'static str,
+}
+
+pub struct StructDescriptor {
+    pub name: &'static str,
+    pub fields: &'static [FieldDescriptor],
+}
+
+pub struct ParamDescriptor {
+    pub name: &'static str,
+    pub c_type: &'static str,
+}
+
+#[derive(Clone, Copy)]
+pub struct FunctionDescriptor {
+    pub name: &'static str,
+    pub params: &'static [ParamDescriptor],
+    pub return_type: &'static str,
+    /// `rustc`'s `target_arch` this symbol is only compiled for, or `None`
+    /// if it's compiled for every target (see the `#[cfg(target_arch)]`
+    /// gates on the ABI-variant modules in `lib.rs`). `build.rs` filters on
+    /// this so the generated header never declares a prototype for a
+    /// symbol the target being built for doesn't actually define.
+    pub arch: Option<&'static str>,
+}
+
+/// Renders the registered structs and functions as a standalone C header.
+pub fn render(structs: &[StructDescriptor], functions: &[FunctionDescriptor]) -> String {
+    let mut out = String::new();
+    out.push_str("/* Generated by build.rs. Do not edit by hand. */\n");
+    out.push_str("#ifndef TESTLIB_H\n");
+    out.push_str("#define TESTLIB_H\n\n");
+    out.push_str("#include <stdint.h>\n");
+    out.push_str("#include <stddef.h>\n");
+    out.push_str("#include <stdbool.h>\n\n");
+    out.push_str("#ifdef __cplusplus\n");
+    out.push_str("extern \"C\" {\n");
+    out.push_str("#endif\n\n");
+
+    for s in structs {
+        out.push_str("typedef struct {\n");
+        for field in s.fields {
+            out.push_str(&format!("    {} {};\n", field.c_type, field.name));
+        }
+        out.push_str(&format!("}} {};\n\n", s.name));
+    }
+
+    for f in functions {
+        let params = if f.params.is_empty() {
+            "void".to_string()
+        } else {
+            f.params
+                .iter()
+                .map(|p| format!("{} {}", p.c_type, p.name))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        out.push_str(&format!("{} {}({});\n", f.return_type, f.name, params));
+    }
+
+    out.push_str("\n#ifdef __cplusplus\n");
+    out.push_str("}\n");
+    out.push_str("#endif\n\n");
+    out.push_str("#endif /* TESTLIB_H */\n");
+    out
+}
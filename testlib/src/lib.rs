@@ -1,37 +1,157 @@
 #![allow(clippy::missing_safety_doc)]
-macro_rules! impl_tuple {
-    ($struct_name:ident, $swap_fn:ident, $type:ty) => {
+// Every exported symbol here is an `extern` function over primitive or
+// `repr(C)` types with no allocation or I/O, so none of it actually needs
+// `std`. Dropping the dependency lets the crate be linked into freestanding
+// environments such as firmware or a Rust-in-kernel module. `header`/
+// `registry` build the generated-header machinery on top of `alloc`'s
+// `String`/`Vec`, which isn't available in those environments, so they stay
+// behind the (default-on) "std" feature.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+pub mod header;
+#[cfg(feature = "std")]
+pub mod registry;
+pub mod view;
+
+// `cdylib`/`staticlib` builds are final link artifacts, so a `no_std` build
+// of either needs its own panic handler regardless of target. `std` builds
+// skip this and get the standard library's handler instead.
+#[cfg(not(feature = "std"))]
+#[panic_handler]
+fn panic(_info: &core::panic::PanicInfo) -> ! {
+    loop {}
+}
+
+macro_rules! impl_tuple_struct {
+    ($struct_name:ident, $type:ty) => {
         #[repr(C)]
         pub struct $struct_name {
             pub a: $type,
             pub b: $type,
         }
+    };
+}
 
+macro_rules! impl_tuple_swap_fn {
+    ($struct_name:ident, $swap_fn:ident, $abi:literal) => {
         #[no_mangle]
-        pub unsafe extern "C" fn $swap_fn(s: *mut $struct_name) {
+        pub unsafe extern $abi fn $swap_fn(s: *mut $struct_name) {
             let s = &mut *s;
-            std::mem::swap(&mut s.a, &mut s.b);
+            core::mem::swap(&mut s.a, &mut s.b);
         }
     };
 }
 
+// `$abi` defaults to "C"; pass it explicitly to emit the same swap body
+// under a different calling convention (see `abi_sysv64`/`abi_win64`/
+// `abi_aapcs` below), which is useful for exercising how a target's ABI
+// passes the struct pointer. The struct itself is only ever passed by
+// pointer, never by value.
+macro_rules! impl_tuple {
+    ($struct_name:ident, $swap_fn:ident, $type:ty) => {
+        impl_tuple!($struct_name, $swap_fn, $type, "C");
+    };
+    ($struct_name:ident, $swap_fn:ident, $type:ty, $abi:literal) => {
+        impl_tuple_struct!($struct_name, $type);
+        impl_tuple_swap_fn!($struct_name, $swap_fn, $abi);
+    };
+}
+
+// `$abi` defaults to "C"; pass it explicitly to emit the same body under a
+// different calling convention.
 macro_rules! impl_sub {
     ($name:ident, $type:ty) => {
+        impl_sub!($name, $type, "C");
+    };
+    ($name:ident, $type:ty, $abi:literal) => {
         #[no_mangle]
-        pub unsafe extern "C" fn $name(x: $type, y: $type) -> $type {
+        pub unsafe extern $abi fn $name(x: $type, y: $type) -> $type {
             x - y
         }
     };
 }
 
+// The `sub_*` functions above wrap on overflow with no way for a C caller
+// to tell, since they can't rely on Rust's debug-only overflow panics.
+// `impl_checked_sub!` emits a sibling that reports success/failure through
+// its return value and only writes `*out` when the subtraction didn't
+// overflow, leaving it untouched otherwise.
+macro_rules! impl_checked_sub {
+    ($name:ident, $type:ty) => {
+        /// # Safety
+        /// `out` must be non-null and valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(x: $type, y: $type, out: *mut $type) -> bool {
+            match x.checked_sub(y) {
+                Some(result) => {
+                    *out = result;
+                    true
+                }
+                None => false,
+            }
+        }
+    };
+}
+
+// Portable ABI wrappers for 128-bit integer types.
+//
+// Rust's by-value ABI for `i128`/`u128` has only recently converged with
+// clang's, so older toolchains and some targets disagree on how a bare
+// 128-bit integer is passed across an `extern "C"` boundary. These
+// wrappers instead pass each 128-bit value as a `#[repr(C)]` struct of two
+// `u64` halves, which has a layout every C compiler agrees on.
+macro_rules! impl_repr128 {
+    ($struct_name:ident) => {
+        #[repr(C)]
+        pub struct $struct_name {
+            pub lo: u64,
+            pub hi: u64,
+        }
+    };
+}
+
+macro_rules! impl_tuple128_portable {
+    ($struct_name:ident, $repr:ident, $swap_fn:ident) => {
+        #[repr(C)]
+        pub struct $struct_name {
+            pub a: $repr,
+            pub b: $repr,
+        }
+
+        #[no_mangle]
+        pub unsafe extern "C" fn $swap_fn(s: *mut $struct_name) {
+            let s = &mut *s;
+            core::mem::swap(&mut s.a, &mut s.b);
+        }
+    };
+}
+
+macro_rules! impl_sub128_portable {
+    ($name:ident, $repr:ident, $type:ty) => {
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(x: $repr, y: $repr) -> $repr {
+            let x = (x.hi as u128) << 64 | x.lo as u128;
+            let y = (y.hi as u128) << 64 | y.lo as u128;
+            let result = (x as $type - y as $type) as u128;
+            $repr {
+                lo: result as u64,
+                hi: (result >> 64) as u64,
+            }
+        }
+    };
+}
+
 impl_tuple!(U8Tuple, swap_u8_tuple, u8);
 impl_tuple!(U16Tuple, swap_u16_tuple, u16);
 impl_tuple!(U32Tuple, swap_u32_tuple, u32);
 impl_tuple!(U64Tuple, swap_u64_tuple, u64);
+impl_tuple!(U128Tuple, swap_u128_tuple, u128);
 impl_tuple!(I8Tuple, swap_i8_tuple, i8);
 impl_tuple!(I16Tuple, swap_i16_tuple, i16);
 impl_tuple!(I32Tuple, swap_i32_tuple, i32);
 impl_tuple!(I64Tuple, swap_i64_tuple, i64);
+impl_tuple!(I128Tuple, swap_i128_tuple, i128);
 impl_tuple!(F32Tuple, swap_f32_tuple, f32);
 impl_tuple!(F64Tuple, swap_f64_tuple, f64);
 
@@ -39,9 +159,203 @@ impl_sub!(sub_u8, u8);
 impl_sub!(sub_u16, u16);
 impl_sub!(sub_u32, u32);
 impl_sub!(sub_u64, u64);
+impl_sub!(sub_u128, u128);
 impl_sub!(sub_i8, i8);
 impl_sub!(sub_i16, i16);
 impl_sub!(sub_i32, i32);
 impl_sub!(sub_i64, i64);
+impl_sub!(sub_i128, i128);
 impl_sub!(sub_f32, f32);
 impl_sub!(sub_f64, f64);
+
+impl_checked_sub!(sub_u8_checked, u8);
+impl_checked_sub!(sub_u16_checked, u16);
+impl_checked_sub!(sub_u32_checked, u32);
+impl_checked_sub!(sub_u64_checked, u64);
+impl_checked_sub!(sub_u128_checked, u128);
+impl_checked_sub!(sub_i8_checked, i8);
+impl_checked_sub!(sub_i16_checked, i16);
+impl_checked_sub!(sub_i32_checked, i32);
+impl_checked_sub!(sub_i64_checked, i64);
+impl_checked_sub!(sub_i128_checked, i128);
+
+impl_repr128!(U128Repr);
+impl_repr128!(I128Repr);
+
+impl_tuple128_portable!(U128TuplePortable, U128Repr, swap_u128_tuple_portable);
+impl_sub128_portable!(sub_u128_portable, U128Repr, u128);
+impl_tuple128_portable!(I128TuplePortable, I128Repr, swap_i128_tuple_portable);
+impl_sub128_portable!(sub_i128_portable, I128Repr, i128);
+
+// Non-default calling conventions, gated to the architectures that define
+// them. These reuse the structs defined above and exist purely so each
+// ABI's rules for passing and returning integer and float scalars (the
+// `sub_*` functions) and for passing struct pointers (the `swap_*`
+// functions) can be exercised on the targets where they apply. No function
+// here passes or returns a struct by value.
+#[cfg(target_arch = "x86_64")]
+mod abi_sysv64 {
+    use super::*;
+
+    impl_tuple_swap_fn!(U8Tuple, swap_u8_tuple_sysv64, "sysv64");
+    impl_tuple_swap_fn!(U16Tuple, swap_u16_tuple_sysv64, "sysv64");
+    impl_tuple_swap_fn!(U32Tuple, swap_u32_tuple_sysv64, "sysv64");
+    impl_tuple_swap_fn!(U64Tuple, swap_u64_tuple_sysv64, "sysv64");
+    impl_tuple_swap_fn!(I8Tuple, swap_i8_tuple_sysv64, "sysv64");
+    impl_tuple_swap_fn!(I16Tuple, swap_i16_tuple_sysv64, "sysv64");
+    impl_tuple_swap_fn!(I32Tuple, swap_i32_tuple_sysv64, "sysv64");
+    impl_tuple_swap_fn!(I64Tuple, swap_i64_tuple_sysv64, "sysv64");
+    impl_tuple_swap_fn!(F32Tuple, swap_f32_tuple_sysv64, "sysv64");
+    impl_tuple_swap_fn!(F64Tuple, swap_f64_tuple_sysv64, "sysv64");
+
+    impl_sub!(sub_u8_sysv64, u8, "sysv64");
+    impl_sub!(sub_u16_sysv64, u16, "sysv64");
+    impl_sub!(sub_u32_sysv64, u32, "sysv64");
+    impl_sub!(sub_u64_sysv64, u64, "sysv64");
+    impl_sub!(sub_i8_sysv64, i8, "sysv64");
+    impl_sub!(sub_i16_sysv64, i16, "sysv64");
+    impl_sub!(sub_i32_sysv64, i32, "sysv64");
+    impl_sub!(sub_i64_sysv64, i64, "sysv64");
+    impl_sub!(sub_f32_sysv64, f32, "sysv64");
+    impl_sub!(sub_f64_sysv64, f64, "sysv64");
+}
+
+#[cfg(target_arch = "x86_64")]
+mod abi_win64 {
+    use super::*;
+
+    impl_tuple_swap_fn!(U8Tuple, swap_u8_tuple_win64, "win64");
+    impl_tuple_swap_fn!(U16Tuple, swap_u16_tuple_win64, "win64");
+    impl_tuple_swap_fn!(U32Tuple, swap_u32_tuple_win64, "win64");
+    impl_tuple_swap_fn!(U64Tuple, swap_u64_tuple_win64, "win64");
+    impl_tuple_swap_fn!(I8Tuple, swap_i8_tuple_win64, "win64");
+    impl_tuple_swap_fn!(I16Tuple, swap_i16_tuple_win64, "win64");
+    impl_tuple_swap_fn!(I32Tuple, swap_i32_tuple_win64, "win64");
+    impl_tuple_swap_fn!(I64Tuple, swap_i64_tuple_win64, "win64");
+    impl_tuple_swap_fn!(F32Tuple, swap_f32_tuple_win64, "win64");
+    impl_tuple_swap_fn!(F64Tuple, swap_f64_tuple_win64, "win64");
+
+    impl_sub!(sub_u8_win64, u8, "win64");
+    impl_sub!(sub_u16_win64, u16, "win64");
+    impl_sub!(sub_u32_win64, u32, "win64");
+    impl_sub!(sub_u64_win64, u64, "win64");
+    impl_sub!(sub_i8_win64, i8, "win64");
+    impl_sub!(sub_i16_win64, i16, "win64");
+    impl_sub!(sub_i32_win64, i32, "win64");
+    impl_sub!(sub_i64_win64, i64, "win64");
+    impl_sub!(sub_f32_win64, f32, "win64");
+    impl_sub!(sub_f64_win64, f64, "win64");
+}
+
+#[cfg(target_arch = "arm")]
+mod abi_aapcs {
+    use super::*;
+
+    impl_tuple_swap_fn!(U8Tuple, swap_u8_tuple_aapcs, "aapcs");
+    impl_tuple_swap_fn!(U16Tuple, swap_u16_tuple_aapcs, "aapcs");
+    impl_tuple_swap_fn!(U32Tuple, swap_u32_tuple_aapcs, "aapcs");
+    impl_tuple_swap_fn!(U64Tuple, swap_u64_tuple_aapcs, "aapcs");
+    impl_tuple_swap_fn!(I8Tuple, swap_i8_tuple_aapcs, "aapcs");
+    impl_tuple_swap_fn!(I16Tuple, swap_i16_tuple_aapcs, "aapcs");
+    impl_tuple_swap_fn!(I32Tuple, swap_i32_tuple_aapcs, "aapcs");
+    impl_tuple_swap_fn!(I64Tuple, swap_i64_tuple_aapcs, "aapcs");
+    impl_tuple_swap_fn!(F32Tuple, swap_f32_tuple_aapcs, "aapcs");
+    impl_tuple_swap_fn!(F64Tuple, swap_f64_tuple_aapcs, "aapcs");
+
+    impl_sub!(sub_u8_aapcs, u8, "aapcs");
+    impl_sub!(sub_u16_aapcs, u16, "aapcs");
+    impl_sub!(sub_u32_aapcs, u32, "aapcs");
+    impl_sub!(sub_u64_aapcs, u64, "aapcs");
+    impl_sub!(sub_i8_aapcs, i8, "aapcs");
+    impl_sub!(sub_i16_aapcs, i16, "aapcs");
+    impl_sub!(sub_i32_aapcs, i32, "aapcs");
+    impl_sub!(sub_i64_aapcs, i64, "aapcs");
+    impl_sub!(sub_f32_aapcs, f32, "aapcs");
+    impl_sub!(sub_f64_aapcs, f64, "aapcs");
+}
+
+#[cfg(test)]
+mod abi_tests {
+    use super::*;
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn sysv64_sub_and_swap() {
+        unsafe {
+            assert_eq!(abi_sysv64::sub_i32_sysv64(5, 3), 2);
+            let mut t = I32Tuple { a: 1, b: 2 };
+            abi_sysv64::swap_i32_tuple_sysv64(&mut t);
+            assert_eq!((t.a, t.b), (2, 1));
+        }
+    }
+
+    #[cfg(target_arch = "x86_64")]
+    #[test]
+    fn win64_sub_and_swap() {
+        unsafe {
+            assert_eq!(abi_win64::sub_i32_win64(5, 3), 2);
+            let mut t = I32Tuple { a: 1, b: 2 };
+            abi_win64::swap_i32_tuple_win64(&mut t);
+            assert_eq!((t.a, t.b), (2, 1));
+        }
+    }
+
+    #[cfg(target_arch = "arm")]
+    #[test]
+    fn aapcs_sub_and_swap() {
+        unsafe {
+            assert_eq!(abi_aapcs::sub_i32_aapcs(5, 3), 2);
+            let mut t = I32Tuple { a: 1, b: 2 };
+            abi_aapcs::swap_i32_tuple_aapcs(&mut t);
+            assert_eq!((t.a, t.b), (2, 1));
+        }
+    }
+
+    // Regression test for a bug where `impl_tuple128_portable!` swapped the
+    // `lo`/`hi` halves of a single `$repr` value instead of swapping the two
+    // independent values `a` and `b`. A tuple holding `(5, 0)` must come out
+    // as `(0, 5)`, not get corrupted into some multiple of 2**64.
+    #[test]
+    fn u128_tuple_portable_swap_is_independent_of_repr_halves() {
+        unsafe {
+            let mut t = U128TuplePortable {
+                a: U128Repr { lo: 5, hi: 0 },
+                b: U128Repr { lo: 0, hi: 0 },
+            };
+            swap_u128_tuple_portable(&mut t);
+            assert_eq!((t.a.lo, t.a.hi), (0, 0));
+            assert_eq!((t.b.lo, t.b.hi), (5, 0));
+        }
+    }
+
+    #[test]
+    fn i128_tuple_portable_swap_is_independent_of_repr_halves() {
+        unsafe {
+            let mut t = I128TuplePortable {
+                a: I128Repr { lo: 5, hi: 0 },
+                b: I128Repr { lo: 0, hi: 0 },
+            };
+            swap_i128_tuple_portable(&mut t);
+            assert_eq!((t.a.lo, t.a.hi), (0, 0));
+            assert_eq!((t.b.lo, t.b.hi), (5, 0));
+        }
+    }
+
+    #[test]
+    fn checked_sub_reports_overflow_and_leaves_out_untouched() {
+        let mut out: u8 = 123;
+        unsafe {
+            assert!(!sub_u8_checked(0, 1, &mut out));
+        }
+        assert_eq!(out, 123);
+    }
+
+    #[test]
+    fn checked_sub_writes_out_on_success() {
+        let mut out: u8 = 0;
+        unsafe {
+            assert!(sub_u8_checked(5, 3, &mut out));
+        }
+        assert_eq!(out, 2);
+    }
+}
@@ -0,0 +1,314 @@
+//! Central list of every symbol the `impl_tuple!`/`impl_sub!` family of
+//! macros emits, consumed by `build.rs` to generate `testlib.h`.
+//!
+//! This is hand-kept in step with `lib.rs`: each `impl_tuple!`/`impl_sub!`
+//! invocation there should have a matching entry here so the generated
+//! header stays accurate.
+
+use crate::header::{FieldDescriptor, FunctionDescriptor, ParamDescriptor, StructDescriptor};
+
+macro_rules! tuple_struct {
+    ($name:literal, $c_type:literal) => {
+        StructDescriptor {
+            name: $name,
+            fields: &[
+                FieldDescriptor {
+                    name: "a",
+                    c_type: $c_type,
+                },
+                FieldDescriptor {
+                    name: "b",
+                    c_type: $c_type,
+                },
+            ],
+        }
+    };
+}
+
+macro_rules! repr128_struct {
+    ($name:literal) => {
+        StructDescriptor {
+            name: $name,
+            fields: &[
+                FieldDescriptor {
+                    name: "lo",
+                    c_type: "uint64_t",
+                },
+                FieldDescriptor {
+                    name: "hi",
+                    c_type: "uint64_t",
+                },
+            ],
+        }
+    };
+}
+
+pub static STRUCTS: &[StructDescriptor] = &[
+    tuple_struct!("U8Tuple", "uint8_t"),
+    tuple_struct!("U16Tuple", "uint16_t"),
+    tuple_struct!("U32Tuple", "uint32_t"),
+    tuple_struct!("U64Tuple", "uint64_t"),
+    tuple_struct!("U128Tuple", "unsigned __int128"),
+    tuple_struct!("I8Tuple", "int8_t"),
+    tuple_struct!("I16Tuple", "int16_t"),
+    tuple_struct!("I32Tuple", "int32_t"),
+    tuple_struct!("I64Tuple", "int64_t"),
+    tuple_struct!("I128Tuple", "__int128"),
+    tuple_struct!("F32Tuple", "float"),
+    tuple_struct!("F64Tuple", "double"),
+    repr128_struct!("U128Repr"),
+    repr128_struct!("I128Repr"),
+    tuple_struct!("U128TuplePortable", "U128Repr"),
+    tuple_struct!("I128TuplePortable", "I128Repr"),
+    StructDescriptor {
+        name: "SliceU8",
+        fields: &[
+            FieldDescriptor {
+                name: "ptr",
+                c_type: "const uint8_t *",
+            },
+            FieldDescriptor {
+                name: "len",
+                c_type: "size_t",
+            },
+        ],
+    },
+    StructDescriptor {
+        name: "SliceU8Mut",
+        fields: &[
+            FieldDescriptor {
+                name: "ptr",
+                c_type: "uint8_t *",
+            },
+            FieldDescriptor {
+                name: "len",
+                c_type: "size_t",
+            },
+        ],
+    },
+    StructDescriptor {
+        name: "StrView",
+        fields: &[
+            FieldDescriptor {
+                name: "ptr",
+                c_type: "const uint8_t *",
+            },
+            FieldDescriptor {
+                name: "len",
+                c_type: "size_t",
+            },
+        ],
+    },
+];
+
+macro_rules! swap_fn {
+    ($fn_name:literal, $struct_name:literal) => {
+        swap_fn!($fn_name, $struct_name, None)
+    };
+    ($fn_name:literal, $struct_name:literal, $arch:expr) => {
+        FunctionDescriptor {
+            name: $fn_name,
+            params: &[ParamDescriptor {
+                name: "s",
+                c_type: concat!($struct_name, " *"),
+            }],
+            return_type: "void",
+            arch: $arch,
+        }
+    };
+}
+
+macro_rules! sub_fn {
+    ($fn_name:literal, $c_type:literal) => {
+        sub_fn!($fn_name, $c_type, None)
+    };
+    ($fn_name:literal, $c_type:literal, $arch:expr) => {
+        FunctionDescriptor {
+            name: $fn_name,
+            params: &[
+                ParamDescriptor {
+                    name: "x",
+                    c_type: $c_type,
+                },
+                ParamDescriptor {
+                    name: "y",
+                    c_type: $c_type,
+                },
+            ],
+            return_type: $c_type,
+            arch: $arch,
+        }
+    };
+}
+
+macro_rules! checked_sub_fn {
+    ($fn_name:literal, $c_type:literal) => {
+        FunctionDescriptor {
+            name: $fn_name,
+            params: &[
+                ParamDescriptor {
+                    name: "x",
+                    c_type: $c_type,
+                },
+                ParamDescriptor {
+                    name: "y",
+                    c_type: $c_type,
+                },
+                ParamDescriptor {
+                    name: "out",
+                    c_type: concat!($c_type, " *"),
+                },
+            ],
+            return_type: "bool",
+            arch: None,
+        }
+    };
+}
+
+macro_rules! sub128_portable_fn {
+    ($fn_name:literal, $repr_name:literal) => {
+        FunctionDescriptor {
+            name: $fn_name,
+            params: &[
+                ParamDescriptor {
+                    name: "x",
+                    c_type: $repr_name,
+                },
+                ParamDescriptor {
+                    name: "y",
+                    c_type: $repr_name,
+                },
+            ],
+            return_type: $repr_name,
+            arch: None,
+        }
+    };
+}
+
+pub static FUNCTIONS: &[FunctionDescriptor] = &[
+    swap_fn!("swap_u8_tuple", "U8Tuple"),
+    swap_fn!("swap_u16_tuple", "U16Tuple"),
+    swap_fn!("swap_u32_tuple", "U32Tuple"),
+    swap_fn!("swap_u64_tuple", "U64Tuple"),
+    swap_fn!("swap_u128_tuple", "U128Tuple"),
+    swap_fn!("swap_i8_tuple", "I8Tuple"),
+    swap_fn!("swap_i16_tuple", "I16Tuple"),
+    swap_fn!("swap_i32_tuple", "I32Tuple"),
+    swap_fn!("swap_i64_tuple", "I64Tuple"),
+    swap_fn!("swap_i128_tuple", "I128Tuple"),
+    swap_fn!("swap_f32_tuple", "F32Tuple"),
+    swap_fn!("swap_f64_tuple", "F64Tuple"),
+    swap_fn!("swap_u128_tuple_portable", "U128TuplePortable"),
+    swap_fn!("swap_i128_tuple_portable", "I128TuplePortable"),
+    swap_fn!("swap_u8_tuple_sysv64", "U8Tuple", Some("x86_64")),
+    swap_fn!("swap_u16_tuple_sysv64", "U16Tuple", Some("x86_64")),
+    swap_fn!("swap_u32_tuple_sysv64", "U32Tuple", Some("x86_64")),
+    swap_fn!("swap_u64_tuple_sysv64", "U64Tuple", Some("x86_64")),
+    swap_fn!("swap_i8_tuple_sysv64", "I8Tuple", Some("x86_64")),
+    swap_fn!("swap_i16_tuple_sysv64", "I16Tuple", Some("x86_64")),
+    swap_fn!("swap_i32_tuple_sysv64", "I32Tuple", Some("x86_64")),
+    swap_fn!("swap_i64_tuple_sysv64", "I64Tuple", Some("x86_64")),
+    swap_fn!("swap_f32_tuple_sysv64", "F32Tuple", Some("x86_64")),
+    swap_fn!("swap_f64_tuple_sysv64", "F64Tuple", Some("x86_64")),
+    swap_fn!("swap_u8_tuple_win64", "U8Tuple", Some("x86_64")),
+    swap_fn!("swap_u16_tuple_win64", "U16Tuple", Some("x86_64")),
+    swap_fn!("swap_u32_tuple_win64", "U32Tuple", Some("x86_64")),
+    swap_fn!("swap_u64_tuple_win64", "U64Tuple", Some("x86_64")),
+    swap_fn!("swap_i8_tuple_win64", "I8Tuple", Some("x86_64")),
+    swap_fn!("swap_i16_tuple_win64", "I16Tuple", Some("x86_64")),
+    swap_fn!("swap_i32_tuple_win64", "I32Tuple", Some("x86_64")),
+    swap_fn!("swap_i64_tuple_win64", "I64Tuple", Some("x86_64")),
+    swap_fn!("swap_f32_tuple_win64", "F32Tuple", Some("x86_64")),
+    swap_fn!("swap_f64_tuple_win64", "F64Tuple", Some("x86_64")),
+    swap_fn!("swap_u8_tuple_aapcs", "U8Tuple", Some("arm")),
+    swap_fn!("swap_u16_tuple_aapcs", "U16Tuple", Some("arm")),
+    swap_fn!("swap_u32_tuple_aapcs", "U32Tuple", Some("arm")),
+    swap_fn!("swap_u64_tuple_aapcs", "U64Tuple", Some("arm")),
+    swap_fn!("swap_i8_tuple_aapcs", "I8Tuple", Some("arm")),
+    swap_fn!("swap_i16_tuple_aapcs", "I16Tuple", Some("arm")),
+    swap_fn!("swap_i32_tuple_aapcs", "I32Tuple", Some("arm")),
+    swap_fn!("swap_i64_tuple_aapcs", "I64Tuple", Some("arm")),
+    swap_fn!("swap_f32_tuple_aapcs", "F32Tuple", Some("arm")),
+    swap_fn!("swap_f64_tuple_aapcs", "F64Tuple", Some("arm")),
+    sub_fn!("sub_u8", "uint8_t"),
+    sub_fn!("sub_u16", "uint16_t"),
+    sub_fn!("sub_u32", "uint32_t"),
+    sub_fn!("sub_u64", "uint64_t"),
+    sub_fn!("sub_u128", "unsigned __int128"),
+    sub_fn!("sub_i8", "int8_t"),
+    sub_fn!("sub_i16", "int16_t"),
+    sub_fn!("sub_i32", "int32_t"),
+    sub_fn!("sub_i64", "int64_t"),
+    sub_fn!("sub_i128", "__int128"),
+    sub_fn!("sub_f32", "float"),
+    sub_fn!("sub_f64", "double"),
+    sub128_portable_fn!("sub_u128_portable", "U128Repr"),
+    sub128_portable_fn!("sub_i128_portable", "I128Repr"),
+    sub_fn!("sub_u8_sysv64", "uint8_t", Some("x86_64")),
+    sub_fn!("sub_u16_sysv64", "uint16_t", Some("x86_64")),
+    sub_fn!("sub_u32_sysv64", "uint32_t", Some("x86_64")),
+    sub_fn!("sub_u64_sysv64", "uint64_t", Some("x86_64")),
+    sub_fn!("sub_i8_sysv64", "int8_t", Some("x86_64")),
+    sub_fn!("sub_i16_sysv64", "int16_t", Some("x86_64")),
+    sub_fn!("sub_i32_sysv64", "int32_t", Some("x86_64")),
+    sub_fn!("sub_i64_sysv64", "int64_t", Some("x86_64")),
+    sub_fn!("sub_f32_sysv64", "float", Some("x86_64")),
+    sub_fn!("sub_f64_sysv64", "double", Some("x86_64")),
+    sub_fn!("sub_u8_win64", "uint8_t", Some("x86_64")),
+    sub_fn!("sub_u16_win64", "uint16_t", Some("x86_64")),
+    sub_fn!("sub_u32_win64", "uint32_t", Some("x86_64")),
+    sub_fn!("sub_u64_win64", "uint64_t", Some("x86_64")),
+    sub_fn!("sub_i8_win64", "int8_t", Some("x86_64")),
+    sub_fn!("sub_i16_win64", "int16_t", Some("x86_64")),
+    sub_fn!("sub_i32_win64", "int32_t", Some("x86_64")),
+    sub_fn!("sub_i64_win64", "int64_t", Some("x86_64")),
+    sub_fn!("sub_f32_win64", "float", Some("x86_64")),
+    sub_fn!("sub_f64_win64", "double", Some("x86_64")),
+    sub_fn!("sub_u8_aapcs", "uint8_t", Some("arm")),
+    sub_fn!("sub_u16_aapcs", "uint16_t", Some("arm")),
+    sub_fn!("sub_u32_aapcs", "uint32_t", Some("arm")),
+    sub_fn!("sub_u64_aapcs", "uint64_t", Some("arm")),
+    sub_fn!("sub_i8_aapcs", "int8_t", Some("arm")),
+    sub_fn!("sub_i16_aapcs", "int16_t", Some("arm")),
+    sub_fn!("sub_i32_aapcs", "int32_t", Some("arm")),
+    sub_fn!("sub_i64_aapcs", "int64_t", Some("arm")),
+    sub_fn!("sub_f32_aapcs", "float", Some("arm")),
+    sub_fn!("sub_f64_aapcs", "double", Some("arm")),
+    FunctionDescriptor {
+        name: "sum_u8_slice",
+        params: &[ParamDescriptor {
+            name: "slice",
+            c_type: "SliceU8",
+        }],
+        return_type: "uint64_t",
+        arch: None,
+    },
+    FunctionDescriptor {
+        name: "reverse_u8_slice_in_place",
+        params: &[ParamDescriptor {
+            name: "slice",
+            c_type: "SliceU8Mut",
+        }],
+        return_type: "void",
+        arch: None,
+    },
+    FunctionDescriptor {
+        name: "str_len_bytes",
+        params: &[ParamDescriptor {
+            name: "s",
+            c_type: "StrView",
+        }],
+        return_type: "size_t",
+        arch: None,
+    },
+    checked_sub_fn!("sub_u8_checked", "uint8_t"),
+    checked_sub_fn!("sub_u16_checked", "uint16_t"),
+    checked_sub_fn!("sub_u32_checked", "uint32_t"),
+    checked_sub_fn!("sub_u64_checked", "uint64_t"),
+    checked_sub_fn!("sub_u128_checked", "unsigned __int128"),
+    checked_sub_fn!("sub_i8_checked", "int8_t"),
+    checked_sub_fn!("sub_i16_checked", "int16_t"),
+    checked_sub_fn!("sub_i32_checked", "int32_t"),
+    checked_sub_fn!("sub_i64_checked", "int64_t"),
+    checked_sub_fn!("sub_i128_checked", "__int128"),
+];
@@ -0,0 +1,115 @@
+//! Length-carrying `#[repr(C)]` views.
+//!
+//! The scalar functions in the crate root only let C callers pass individual values or
+//! fixed-size tuples. These views add a pointer+length pair so a caller can
+//! hand Rust a slice or a UTF-8 string without going through a NUL-
+//! terminated buffer or a fixed-size struct.
+
+#[repr(C)]
+pub struct SliceU8 {
+    pub ptr: *const u8,
+    pub len: usize,
+}
+
+impl SliceU8 {
+    /// # Safety
+    /// `ptr` must be non-null and valid for reads of `len` bytes.
+    pub unsafe fn as_slice(&self) -> &[u8] {
+        core::slice::from_raw_parts(self.ptr, self.len)
+    }
+}
+
+#[repr(C)]
+pub struct SliceU8Mut {
+    pub ptr: *mut u8,
+    pub len: usize,
+}
+
+impl SliceU8Mut {
+    /// # Safety
+    /// `ptr` must be non-null and valid for reads and writes of `len` bytes.
+    pub unsafe fn as_slice_mut(&mut self) -> &mut [u8] {
+        core::slice::from_raw_parts_mut(self.ptr, self.len)
+    }
+}
+
+#[repr(C)]
+pub struct StrView {
+    pub ptr: *const u8,
+    pub len: usize,
+}
+
+impl StrView {
+    /// # Safety
+    /// `ptr` must be non-null, valid for reads of `len` bytes, and the
+    /// bytes in range must be valid UTF-8.
+    pub unsafe fn as_str(&self) -> &str {
+        core::str::from_utf8_unchecked(core::slice::from_raw_parts(self.ptr, self.len))
+    }
+}
+
+/// # Safety
+/// `slice.ptr` must be non-null and valid for reads of `slice.len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn sum_u8_slice(slice: SliceU8) -> u64 {
+    slice.as_slice().iter().map(|&b| b as u64).sum()
+}
+
+/// # Safety
+/// `slice.ptr` must be non-null and valid for reads and writes of
+/// `slice.len` bytes.
+#[no_mangle]
+pub unsafe extern "C" fn reverse_u8_slice_in_place(mut slice: SliceU8Mut) {
+    slice.as_slice_mut().reverse();
+}
+
+/// # Safety
+/// `s.ptr` must be non-null, valid for reads of `s.len` bytes, and the
+/// bytes in range must be valid UTF-8.
+#[no_mangle]
+pub unsafe extern "C" fn str_len_bytes(s: StrView) -> usize {
+    s.as_str().len()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sum_u8_slice_sums_bytes() {
+        let bytes = [1u8, 2, 3, 4];
+        let slice = SliceU8 {
+            ptr: bytes.as_ptr(),
+            len: bytes.len(),
+        };
+        unsafe {
+            assert_eq!(sum_u8_slice(slice), 10);
+        }
+    }
+
+    #[test]
+    fn reverse_u8_slice_in_place_reverses_bytes() {
+        let mut bytes = [1u8, 2, 3, 4];
+        let slice = SliceU8Mut {
+            ptr: bytes.as_mut_ptr(),
+            len: bytes.len(),
+        };
+        unsafe {
+            reverse_u8_slice_in_place(slice);
+        }
+        assert_eq!(bytes, [4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn str_len_bytes_counts_utf8_bytes_not_chars() {
+        let s = "héllo";
+        let view = StrView {
+            ptr: s.as_ptr(),
+            len: s.len(),
+        };
+        unsafe {
+            assert_eq!(str_len_bytes(view), s.len());
+        }
+        assert_eq!(s.len(), 6);
+    }
+}